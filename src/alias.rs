@@ -0,0 +1,74 @@
+//! Weighted discrete sampling via Vose's alias method.
+
+use crate::Rng;
+
+/// A precomputed table for sampling indices `0 .. weights.len()` with
+/// probability proportional to the given weights.
+///
+/// Building the table costs `O(n)` time and space; sampling from it costs
+/// `O(1)` time.
+
+pub struct AliasTable {
+  prob: Box<[f64]>,
+  alias: Box<[u32]>,
+}
+
+impl AliasTable {
+  /// Builds an alias table from the given weights using Vose's algorithm.
+  ///
+  /// Panics if `weights` is empty, any weight is negative or non-finite, or
+  /// the weights sum to zero.
+
+  pub fn new(weights: &[f64]) -> Self {
+    let n = weights.len();
+
+    assert!(n != 0, "AliasTable::new: weights must be nonempty");
+
+    let sum: f64 = weights.iter().fold(0.0, |a, &w| {
+      assert!(w >= 0.0 && w.is_finite(), "AliasTable::new: weights must be nonnegative and finite");
+      a + w
+    });
+
+    assert!(sum > 0.0, "AliasTable::new: weights must not all be zero");
+
+    let mut scaled: Vec<f64> = weights.iter().map(|&w| w * (n as f64) / sum).collect();
+    let mut prob = vec![0.0; n].into_boxed_slice();
+    let mut alias = vec![0u32; n].into_boxed_slice();
+
+    let mut small: Vec<usize> = Vec::new();
+    let mut large: Vec<usize> = Vec::new();
+
+    for (i, &p) in scaled.iter().enumerate() {
+      if p < 1.0 { small.push(i); } else { large.push(i); }
+    }
+
+    while let (Some(&s), Some(&l)) = (small.last(), large.last()) {
+      let _ = small.pop();
+      let _ = large.pop();
+      prob[s] = scaled[s];
+      alias[s] = l as u32;
+      scaled[l] -= 1.0 - scaled[s];
+      if scaled[l] < 1.0 { small.push(l); } else { large.push(l); }
+    }
+
+    while let Some(l) = large.pop() {
+      prob[l] = 1.0;
+    }
+
+    while let Some(s) = small.pop() {
+      prob[s] = 1.0;
+    }
+
+    Self { prob, alias }
+  }
+
+  /// Samples an index `0 .. weights.len()` with probability proportional to
+  /// its weight.
+
+  pub fn sample(&self, rng: &mut Rng) -> usize {
+    let n = self.prob.len();
+    let i = rng.below(n as u64) as usize;
+
+    if rng.f64() < self.prob[i] { i } else { self.alias[i] as usize }
+  }
+}
@@ -0,0 +1,286 @@
+#![allow(clippy::excessive_precision)]
+
+//! Precomputed Ziggurat tables used by [`crate::Rng::normal`] and
+//! [`crate::Rng::exp`].
+//!
+//! Each distribution is partitioned into 256 horizontal layers of equal
+//! area. `X[i]` is the right edge of layer `i` and `F[i]` is the density at
+//! that edge, with `X[0]` the tail cutoff and `X[256] == 0.0`. Layer `0` is
+//! the one adjoining the tail and is handled by a dedicated fallback sampler
+//! rather than the table.
+
+#[rustfmt::skip]
+pub(crate) const ZIG_NORM_X: [f64; 257] = [
+  3.65530124100045661e+00, 3.45050066778534337e+00, 3.32152086504116317e+00, 3.22589469663900585e+00,
+  3.14924620460125526e+00, 3.08491608411935880e+00, 3.02925770562671115e+00, 2.98005081234522828e+00,
+  2.93584016952051297e+00, 2.89561862772395662e+00, 2.85865933726085508e+00, 2.82441999248994957e+00,
+  2.79248486913133975e+00, 2.76252803201324504e+00, 2.73428904833781594e+00, 2.70755642024309262e+00,
+  2.68215596229316544e+00, 2.65794244872268326e+00, 2.63479348291051396e+00, 2.61260491382327453e+00,
+  2.59128735238573338e+00, 2.57076348476632210e+00, 2.55096597283686544e+00, 2.53183579386927304e+00,
+  2.51332091333854013e+00, 2.49537521351339064e+00, 2.47795762071136449e+00, 2.46103138847125269e+00,
+  2.44456350427519586e+00, 2.42852419504466743e+00, 2.41288651225465234e+00, 2.39762598171720187e+00,
+  2.38272030626718578e+00, 2.36814911201255240e+00, 2.35389373068325147e+00, 2.33993701206729199e+00,
+  2.32626316166125058e+00, 2.31285759956096548e+00, 2.29970683733180348e+00, 2.28679837016856169e+00,
+  2.27412058211415902e+00, 2.26166266247784797e+00, 2.24941453189602258e+00, 2.23736677672606454e+00,
+  2.22551059066702317e+00, 2.21383772266893653e+00, 2.20234043033199445e+00, 2.19101143811295129e+00,
+  2.17984389975341264e+00, 2.16883136442633218e+00, 2.15796774616593368e+00, 2.14724729620460364e+00,
+  2.13666457788981745e+00, 2.12621444389636949e+00, 2.11589201548525629e+00, 2.10569266359151319e+00,
+  2.09561199154988387e+00, 2.08564581929018678e+00, 2.07579016885406142e+00, 2.06604125110199544e+00,
+  2.05639545349449371e+00, 2.04684932884429038e+00, 2.03739958494787654e+00, 2.02804307501460368e+00,
+  2.01877678882036404e+00, 2.00959784452052981e+00, 2.00050348106362241e+00, 1.99149105115316738e+00,
+  1.98255801471046977e+00, 1.97370193279575057e+00, 1.96492046194923708e+00, 1.95621134891751525e+00,
+  1.94757242573374434e+00, 1.93900160512328013e+00, 1.93049687620889454e+00, 1.92205630049212295e+00,
+  1.91367800808939492e+00, 1.90536019420349989e+00, 1.89710111581263874e+00, 1.88889908856086319e+00,
+  1.88075248383507576e+00, 1.87265972601502573e+00, 1.86461928988386449e+00, 1.85662969818784540e+00,
+  1.84868951933468706e+00, 1.84079736522095638e+00, 1.83295188917959773e+00, 1.82515178403942779e+00,
+  1.81739578028904902e+00, 1.80968264433821768e+00, 1.80201117687022228e+00, 1.79438021127931524e+00,
+  1.78678861218767948e+00, 1.77923527403681314e+00, 1.77171911974858709e+00, 1.76423909945156399e+00,
+  1.75679418926848663e+00, 1.74938339016111422e+00, 1.74200572682886534e+00, 1.73466024665794882e+00,
+  1.72734601871790172e+00, 1.72006213280264286e+00, 1.71280769851335513e+00, 1.70558184438066629e+00,
+  1.69838371702377189e+00, 1.69121248034428273e+00, 1.68406731475272320e+00, 1.67694741642572631e+00,
+  1.66985199659209194e+00, 1.66278028084598239e+00, 1.65573150848562811e+00, 1.64870493187601030e+00,
+  1.64169981583406788e+00, 1.63471543703506561e+00, 1.62775108343881780e+00, 1.62080605373454834e+00,
+  1.61387965680321055e+00, 1.60697121119616604e+00, 1.60008004462916098e+00, 1.59320549349059792e+00,
+  1.58634690236313736e+00, 1.57950362355771512e+00, 1.57267501665908860e+00, 1.56586044808206926e+00,
+  1.55905929063762616e+00, 1.55227092310807513e+00, 1.54549472983059522e+00, 1.53873010028833779e+00,
+  1.53197642870841433e+00, 1.52523311366606773e+00, 1.51849955769435208e+00, 1.51177516689865188e+00,
+  1.50505935057539664e+00, 1.49835152083432410e+00, 1.49165109222366210e+00, 1.48495748135760097e+00,
+  1.47827010654543622e+00, 1.47158838742175879e+00, 1.46491174457707252e+00, 1.45823959918821977e+00,
+  1.45157137264798464e+00, 1.44490648619324702e+00, 1.43824436053104221e+00, 1.43158441546188153e+00,
+  1.42492606949966594e+00, 1.41826873948752064e+00, 1.41161184020885178e+00, 1.40495478399291684e+00,
+  1.39829698031416694e+00, 1.39163783538460506e+00, 1.38497675173836798e+00, 1.37831312780771609e+00,
+  1.37164635748957342e+00, 1.36497582970172848e+00, 1.35830092792776513e+00, 1.35162102974974041e+00,
+  1.34493550636758608e+00, 1.33824372210414877e+00, 1.33154503389473033e+00, 1.32483879075991751e+00,
+  1.31812433326042977e+00, 1.31140099293262757e+00, 1.30466809170324627e+00, 1.29792494128182678e+00,
+  1.29117084252921410e+00, 1.28440508480038940e+00, 1.27762694525978127e+00, 1.27083568816707437e+00,
+  1.26403056413138981e+00, 1.25721080933156748e+00, 1.25037564470010198e+00, 1.24352427506811014e+00,
+  1.23665588826850525e+00, 1.22976965419433548e+00, 1.22286472380900357e+00, 1.21594022810482638e+00,
+  1.20899527700610188e+00, 1.20202895821253830e+00, 1.19504033597855530e+00, 1.18802844982357825e+00,
+  1.18099231316803865e+00, 1.17393091188932153e+00, 1.16684320279139064e+00, 1.15972811198126502e+00,
+  1.15258453314488873e+00, 1.14541132571424087e+00, 1.13820731291677935e+00, 1.13097127969743960e+00,
+  1.12370197050247600e+00, 1.11639808691336873e+00, 1.10905828511784033e+00, 1.10168117320370818e+00,
+  1.09426530825982304e+00, 1.08680919326669079e+00, 1.07931127375750946e+00, 1.07176993422827227e+00,
+  1.06418349427322179e+00, 1.05655020441928071e+00, 1.04886824163007519e+00, 1.04113570444674908e+00,
+  1.03335060772888698e+00, 1.02551087695444743e+00, 1.01761434203256518e+00, 1.00965873057731881e+00,
+  1.00164166058394133e+00, 9.93560632441363056e-01, 9.85413020206213908e-01, 9.77196062053299119e-01,
+  9.68906849805844628e-01, 9.60542317435189474e-01, 9.52099228403735287e-01, 9.43574161706413950e-01,
+  9.34963496444176312e-01, 9.26263394737405932e-01, 9.17469782756928698e-01, 9.08578329614450508e-01,
+  8.99584423811624179e-01, 8.90483146896002498e-01, 8.81269243911027456e-01, 8.71937090153567351e-01,
+  8.62480653663361574e-01, 8.52893452760275950e-01, 8.43168507812651624e-01, 8.33298286256967558e-01,
+  8.23274639687414211e-01, 8.13088731583150093e-01, 8.02730953926973023e-01, 7.92190830573284321e-01,
+  7.81456904720616241e-01, 7.70516607200933401e-01, 7.59356101468393496e-01, 7.47960100090762081e-01,
+  7.36311646128683717e-01, 7.24391850906471069e-01, 7.12179577154206433e-01, 6.99651053075524110e-01,
+  6.86779398186907253e-01, 6.73534035211956650e-01, 6.59879953028828736e-01, 6.45776772311915659e-01,
+  6.31177545940806217e-01, 6.16027196998515270e-01, 6.00260452462472505e-01, 5.83799060585547758e-01,
+  5.66547966893362176e-01, 5.48389935373029713e-01, 5.29177775824281382e-01, 5.08722750696987247e-01,
+  4.86776619012823708e-01, 4.63002524201955090e-01, 4.36925043486953879e-01, 4.07838064783964560e-01,
+  3.74617844183122828e-01, 3.35289464688768191e-01, 2.85795085428214468e-01, 2.14958538899002516e-01,
+  0.00000000000000000e+00,
+];
+
+#[rustfmt::skip]
+pub(crate) const ZIG_NORM_F: [f64; 257] = [
+  1.25500768711019907e-03, 2.59809335181851019e-03, 4.02089635047123400e-03, 5.49894899456244372e-03,
+  7.02081599849570855e-03, 8.57972323471156359e-03, 1.01711385481623590e-02, 1.17917938948036340e-02,
+  1.34392096625618514e-02, 1.51114337665668123e-02, 1.68068858713341929e-02, 1.85242582888823226e-02,
+  2.02624497441305229e-02, 2.20205193226795312e-02, 2.37976533970079666e-02, 2.55931412222481849e-02,
+  2.74063565112346412e-02, 2.92367432471278207e-02, 3.10838045705727622e-02, 3.29470939436567883e-02,
+  3.48262080305218533e-02, 3.67207808931024060e-02, 3.86304792088245433e-02, 4.05549982926752053e-02,
+  4.24940587597347416e-02, 4.44474037030419961e-02, 4.64147962900934644e-02, 4.83960177024148133e-02,
+  5.03908653585545541e-02, 5.23991513729666972e-02, 5.44207012125753203e-02, 5.64553525200629583e-02,
+  5.85029540786104960e-02, 6.05633648973143285e-02, 6.26364534000927731e-02, 6.47220967037746286e-02,
+  6.68201799733925822e-02, 6.89305958446013584e-02, 7.10532439046938358e-02, 7.31880302249685727e-02,
+  7.53348669382623432e-02, 7.74936718563437599e-02, 7.96643681226028300e-02, 8.18468838960917239e-02,
+  8.40411520634961329e-02, 8.62471099760607207e-02, 8.84646992088702483e-02, 9.06938653402108719e-02,
+  9.29345577490129499e-02, 9.51867294286151272e-02, 9.74503368152948996e-02, 9.97253396301890221e-02,
+  1.02011700733381866e-01, 1.04309385989074521e-01, 1.06618364140865077e-01, 1.08938606696273699e-01,
+  1.11270087819736740e-01, 1.13612784233373551e-01, 1.15966675124900401e-01, 1.18331742062127696e-01,
+  1.20707968913532346e-01, 1.23095341774445630e-01, 1.25493848898441007e-01, 1.27903480633545064e-01,
+  1.30324229362929367e-01, 1.32756089449772796e-01, 1.35199057186011357e-01, 1.37653130744717689e-01,
+  1.40118310135875612e-01, 1.42594597165334958e-01, 1.45081995396750818e-01, 1.47580510116327834e-01,
+  1.50090148300205478e-01, 1.52610918584334260e-01, 1.55142831236704692e-01, 1.57685898131803337e-01,
+  1.60240132727179768e-01, 1.62805550042018271e-01, 1.65382166637617123e-01, 1.67970000599685726e-01,
+  1.70569071522377852e-01, 1.73179400493985752e-01, 1.75801010084226300e-01, 1.78433924333056132e-01,
+  1.81078168740958251e-01, 1.83733770260647189e-01, 1.86400757290145103e-01, 1.89079159667184887e-01,
+  1.91769008664900986e-01, 1.94470336988771864e-01, 1.97183178774782086e-01, 1.99907569588775230e-01,
+  2.02643546426971660e-01, 2.05391147717628730e-01, 2.08150413323823447e-01, 2.10921384547340440e-01,
+  2.13704104133650680e-01, 2.16498616277968764e-01, 2.19304966632379111e-01, 2.22123202314023632e-01,
+  2.24953371914345529e-01, 2.27795525509386171e-01, 2.30649714671134043e-01, 2.33515992479926798e-01,
+  2.36394413537909465e-01, 2.39285033983553719e-01, 2.42187911507245363e-01, 2.45103105367948659e-01,
+  2.48030676410958550e-01, 2.50970687086753463e-01, 2.53923201470963311e-01, 2.56888285285469253e-01,
+  2.59866005920654120e-01, 2.62856432458823619e-01, 2.65859635698821484e-01, 2.68875688181862416e-01,
+  2.71904664218610537e-01, 2.74946639917531488e-01, 2.78001693214549706e-01, 2.81069903904044294e-01,
+  2.84151353671219076e-01, 2.87246126125885126e-01, 2.90354306837696474e-01, 2.93475983372882665e-01,
+  2.96611245332523699e-01, 2.99760184392416917e-01, 3.02922894344587024e-01, 3.06099471140495183e-01,
+  3.09290012936004499e-01, 3.12494620138164425e-01, 3.15713395453878609e-01, 3.18946443940525848e-01,
+  3.22193873058606695e-01, 3.25455792726493109e-01, 3.28732315377362472e-01, 3.32023556018402444e-01,
+  3.35329632292377555e-01, 3.38650664541653657e-01, 3.41986775874782267e-01, 3.45338092235752236e-01,
+  3.48704742476022234e-01, 3.52086858429454419e-01, 3.55484574990276636e-01, 3.58898030194207018e-01,
+  3.62327365302883952e-01, 3.65772724891751611e-01, 3.69234256941560590e-01, 3.72712112933652473e-01,
+  3.76206447949207656e-01, 3.79717420772645475e-01, 3.83245193999378286e-01, 3.86789934148132508e-01,
+  3.90351811778063273e-01, 3.93931001610902942e-01, 3.97527682658398829e-01, 4.01142038355312036e-01,
+  4.04774256698265167e-01, 4.08424530390746887e-01, 4.12093056994599805e-01, 4.15780039088339770e-01,
+  4.19485684432678030e-01, 4.23210206143641854e-01, 4.26953822873715860e-01, 4.30716759001454674e-01,
+  4.34499244830049425e-01, 4.38301516795362278e-01, 4.42123817683980458e-01, 4.45966396861879666e-01,
+  4.49829510514329123e-01, 4.53713421897715519e-01, 4.57618401604013458e-01, 4.61544727838683277e-01,
+  4.65492686712835002e-01, 4.69462572550561508e-01, 4.73454688212412433e-01, 4.77469345436055570e-01,
+  4.81506865195254596e-01, 4.85567578078381468e-01, 4.89651824687779846e-01, 4.93759956061402605e-01,
+  4.97892334118264168e-01, 5.02049332129376302e-01, 5.06231335215977962e-01, 5.10438740877024566e-01,
+  5.14671959548072433e-01, 5.18931415193883194e-01, 5.23217545937279538e-01, 5.27530804727013858e-01,
+  5.31871660047664951e-01, 5.36240596674858794e-01, 5.40638116479422171e-01, 5.45064739284423450e-01,
+  5.49521003779442130e-01, 5.54007468496838129e-01, 5.58524712855272165e-01, 5.63073338276265600e-01,
+  5.67653969380191081e-01, 5.72267255268759656e-01, 5.76913870901829884e-01, 5.81594518577221775e-01,
+  5.86309929523181950e-01, 5.91060865614242203e-01, 5.95848121222449900e-01, 6.00672525217358477e-01,
+  6.05534943129766412e-01, 6.10436279496023526e-01, 6.15377480401815169e-01, 6.20359536246734788e-01,
+  6.25383484753713526e-01, 6.30450414250560365e-01, 6.35561467254541546e-01, 6.40717844395198521e-01,
+  6.45920808715565009e-01, 6.51171690397737035e-01, 6.56471891965531906e-01, 6.61822894024941322e-01,
+  6.67226261612479443e-01, 6.72683651232651725e-01, 6.78196818678988067e-01, 6.83767627748859463e-01,
+  6.89398059981204958e-01, 6.95090225569065634e-01, 7.00846375626367202e-01, 7.06668916021897520e-01,
+  7.12560423034386825e-01, 7.18523661132966174e-01, 7.24561603249590069e-01, 7.30677453987575776e-01,
+  7.36874676307639032e-01, 7.43157022355542263e-01, 7.49528569251608046e-01, 7.55993760862612163e-01,
+  7.62557456835676106e-01, 7.69224990512197948e-01, 7.76002237786356019e-01, 7.82895699568278425e-01,
+  7.89912601315796103e-01, 7.97061014197631201e-01, 8.04350003974418470e-01, 8.11789815828747874e-01,
+  8.19392106445987589e-01, 8.27170239126009510e-01, 8.35139664373625745e-01, 8.43318418574136919e-01,
+  8.51727789243651445e-01, 8.60393220917334056e-01, 8.69345578319077217e-01, 8.78622957153309292e-01,
+  8.88273366320683877e-01, 8.98358860375296331e-01, 9.08962220919475206e-01, 9.20198433560888596e-01,
+  9.32236012004133641e-01, 9.45341054311137019e-01, 9.59983276074756531e-01, 9.77161257598204935e-01,
+  1.00000000000000000e+00,
+];
+
+#[rustfmt::skip]
+pub(crate) const ZIG_EXP_X: [f64; 257] = [
+  7.70156560929774336e+00, 6.94551699880343154e+00, 6.48289859171377536e+00, 6.14871720632106467e+00,
+  5.88672565852146779e+00, 5.67101751737882331e+00, 5.48752182434311209e+00, 5.32774384371493070e+00,
+  5.18616138422093176e+00, 5.05898222621272087e+00, 4.94348950960864997e+00, 4.83767005067525968e+00,
+  4.73999050492391127e+00, 4.64925599717896620e+00, 4.56451725692384525e+00, 4.48500756758325281e+00,
+  4.41009873503458039e+00, 4.33926958135816143e+00, 4.27208291767151316e+00, 4.20816839705360746e+00,
+  4.14720953290662830e+00, 4.08893372446733672e+00, 4.03310449035299179e+00, 3.97951534830259712e+00,
+  3.92798493930229542e+00, 3.87835310425161461e+00, 3.83047769819044603e+00, 3.78423198167076746e+00,
+  3.73950246814591880e+00, 3.69618713491227746e+00, 3.65419392630158191e+00, 3.61343949362419981e+00,
+  3.57384812828559406e+00, 3.53535085358005352e+00, 3.49788464764670470e+00, 3.46139177548431354e+00,
+  3.42581921214957053e+00, 3.39111814259179534e+00, 3.35724352621525712e+00, 3.32415371636543089e+00,
+  3.29181012662573780e+00, 3.26017693717646928e+00, 3.22922083557630701e+00, 3.19891078723214761e+00,
+  3.16921783156581549e+00, 3.14011490049881115e+00, 3.11157665638363357e+00, 3.08357934693233871e+00,
+  3.05610067504565430e+00, 3.02911968174172364e+00, 3.00261664063259737e+00, 2.97657296260699056e+00,
+  2.95097110955626096e+00, 2.92579451613235353e+00, 2.90102751865603015e+00, 2.87665529040463364e+00,
+  2.85266378260387699e+00, 2.82903967053020233e+00, 2.80577030420106643e+00, 2.78284366319186072e+00,
+  2.76024831517140834e+00, 2.73797337779429961e+00, 2.71600848362875791e+00, 2.69434374783404484e+00,
+  2.67296973833237006e+00, 2.65187744824747496e+00, 2.63105827040595397e+00, 2.61050397371849785e+00,
+  2.59020668127685205e+00, 2.57015885001880129e+00, 2.55035325182809380e+00, 2.53078295594923874e+00,
+  2.51144131260864656e+00, 2.49232193774390698e+00, 2.47341869875219622e+00, 2.45472570117703315e+00,
+  2.43623727625997555e+00, 2.41794796929046019e+00, 2.39985252869292509e+00, 2.38194589579570870e+00,
+  2.36422319523100821e+00, 2.34667972591954888e+00, 2.32931095259751819e+00, 2.31211249784687034e+00,
+  2.29508013459332494e+00, 2.27820977903927790e+00, 2.26149748400150852e+00, 2.24493943262594664e+00,
+  2.22853193245395875e+00, 2.21227140981660630e+00, 2.19615440453513022e+00, 2.18017756490758430e+00,
+  2.16433764296304476e+00, 2.14863148996621423e+00, 2.13305605215649363e+00, 2.11760836670676778e+00,
+  2.10228555788821447e+00, 2.08708483342840534e+00, 2.07200348105089827e+00, 2.05703886518530954e+00,
+  2.04218842383764487e+00, 2.02744966561134987e+00, 2.01282016687018928e+00, 1.99829756903466671e+00,
+  1.98387957600424070e+00, 1.96956395169809584e+00, 1.95534851770771034e+00, 1.94123115105488453e+00,
+  1.92720978204930216e+00, 1.91328239224006635e+00, 1.89944701245600411e+00, 1.88570172092984256e+00,
+  1.87204464150167116e+00, 1.85847394189736725e+00, 1.84498783207792960e+00, 1.83158456265589731e+00,
+  1.81826242337525490e+00, 1.80501974165143153e+00, 1.79185488116819358e+00, 1.77876624052841037e+00,
+  1.76575225195583596e+00, 1.75281138004521142e+00, 1.73994212055812825e+00, 1.72714299926223758e+00,
+  1.71441257081150700e+00, 1.70174941766535226e+00, 1.68915214904457045e+00, 1.67661939992211328e+00,
+  1.66414983004682537e+00, 1.65174212299836598e+00, 1.63939498527161165e+00, 1.62710714538891410e+00,
+  1.61487735303865998e+00, 1.60270437823864209e+00, 1.59058701052281504e+00, 1.57852405815006280e+00,
+  1.56651434733365802e+00, 1.55455672149014212e+00, 1.54265004050639432e+00, 1.53079318002370601e+00,
+  1.51898503073770219e+00, 1.50722449771299627e+00, 1.49551049971148342e+00, 1.48384196853320982e+00,
+  1.47221784836878022e+00, 1.46063709516227469e+00, 1.44909867598367814e+00, 1.43760156840982445e+00,
+  1.42614475991287781e+00, 1.41472724725537780e+00, 1.40334803589087520e+00, 1.39200613936919582e+00,
+  1.38070057874535812e+00, 1.36943038199117018e+00, 1.35819458340852228e+00, 1.34699222304338018e+00,
+  1.33582234609946604e+00, 1.32468400235059702e+00, 1.31357624555062724e+00, 1.30249813283991278e+00,
+  1.29144872414718548e+00, 1.28042708158569019e+00, 1.26943226884239047e+00, 1.25846335055901171e+00,
+  1.24751939170363024e+00, 1.23659945693146511e+00, 1.22570260993346158e+00, 1.21482791277118496e+00,
+  1.20397442519646414e+00, 1.19314120395413892e+00, 1.18232730206616576e+00, 1.17153176809523463e+00,
+  1.16075364538592818e+00, 1.14999197128133068e+00, 1.13924577631284918e+00, 1.12851408336085535e+00,
+  1.11779590678358631e+00, 1.10709025151155016e+00, 1.09639611210448162e+00, 1.08571247176765273e+00,
+  1.07503830132410627e+00, 1.06437255813908727e+00, 1.05371418499264857e+00, 1.04306210889606699e+00,
+  1.03241523984732408e+00, 1.02177246952049838e+00, 1.01113266988344752e+00, 1.00049469173764960e+00,
+  9.89857363173506477e-01, 9.79219487933771893e-01, 9.68579843677068064e-01, 9.57937180132665600e-01,
+  9.47290217136820156e-01, 9.36637642539974835e-01, 9.25978109973037533e-01, 9.15310236459696225e-01,
+  9.04632599860347830e-01, 8.93943736131641464e-01, 8.83242136383863063e-01, 8.72526243716386651e-01,
+  8.61794449809136887e-01, 8.51045091245431706e-01, 8.40276445538630568e-01, 8.29486726831662025e-01,
+  8.18674081234670892e-01, 8.07836581761630201e-01, 7.96972222821714871e-01, 7.86078914215417646e-01,
+  7.75154474578670816e-01, 7.64196624210451314e-01, 7.53202977210313374e-01, 7.42171032841751788e-01,
+  7.31098166024990337e-01, 7.19981616848348938e-01, 7.08818478970346111e-01, 6.97605686764630417e-01,
+  6.86340001036034320e-01, 6.75017993107735581e-01, 6.63636027045668486e-01, 6.52190239745720768e-01,
+  6.40676518560283514e-01, 6.29090476081415995e-01, 6.17427421625672879e-01, 6.05682328877267806e-01,
+  5.93849799037482362e-01, 5.81924018693577461e-01, 5.69898711452735407e-01, 5.57767082176232609e-01,
+  5.45521752383448133e-01, 5.33154685057425026e-01, 5.20657096650399720e-01, 5.08019353527322970e-01,
+  4.95230849354018232e-01, 4.82279858972715192e-01, 4.69153363023898040e-01, 4.55836835844063537e-01,
+  4.42313986810637383e-01, 4.28566442045957785e-01, 4.14573348821616783e-01, 4.00310878492022348e-01,
+  3.85751594342774617e-01, 3.70863636778527961e-01, 3.55609657186283845e-01, 3.39945399178907093e-01,
+  3.23817774047325091e-01, 3.07162192207033835e-01, 2.89898768026727482e-01, 2.71926760086700303e-01,
+  2.53116135419830857e-01, 2.33294217288816691e-01, 2.12223424720411391e-01, 1.89561652900680860e-01,
+  1.64785500447883981e-01, 1.37023295365475306e-01, 1.04625906433766111e-01, 6.37245893619011183e-02,
+  0.00000000000000000e+00,
+];
+
+#[rustfmt::skip]
+pub(crate) const ZIG_EXP_F: [f64; 257] = [
+  4.52118787119196316e-04, 9.62942363635158277e-04, 1.52937122558907440e-03, 2.13622034310300465e-03,
+  2.77605157249657519e-03, 3.44435879751883332e-03, 4.13808638295789025e-03, 4.85501132927183918e-03,
+  5.59343671245816909e-03, 6.35202114472893566e-03, 7.12967584154312103e-03, 7.92549856588935925e-03,
+  8.73872915997765676e-03, 9.56871843637536502e-03, 1.04149057170286417e-02, 1.12768021822783164e-02,
+  1.21539782472082844e-02, 1.30460538050773886e-02, 1.39526905593862667e-02, 1.48735859083599931e-02,
+  1.58084680038741224e-02, 1.67570917129242179e-02, 1.77192352824745679e-02, 1.86946975594188165e-02,
+  1.96832956536545506e-02, 2.06848629585466640e-02, 2.16992474623715036e-02, 2.27263102987306162e-02,
+  2.37659244947865493e-02, 2.48179738844649563e-02, 2.58823521601626166e-02, 2.69589620414820673e-02,
+  2.80477145434280609e-02, 2.91485283296034278e-02, 3.02613291384198763e-02, 3.13860492723329818e-02,
+  3.25226271417258031e-02, 3.36710068563825168e-02, 3.48311378585737241e-02, 3.60029745926663813e-02,
+  3.71864762069104224e-02, 3.83816062836704955e-02, 3.95883325948875320e-02, 4.08066268799891843e-02,
+  4.20364646438356124e-02, 4.32778249725985220e-02, 4.45306903657371189e-02, 4.57950465824617237e-02,
+  4.70708825012706999e-02, 4.83581899913143737e-02, 4.96569637944847056e-02, 5.09672014172552756e-02,
+  5.22889030314055772e-02, 5.36220713828590018e-02, 5.49667117079473133e-02, 5.63228316564876111e-02,
+  5.76904412211221088e-02, 5.90695526724277289e-02, 6.04601804993526518e-02, 6.18623413545813308e-02,
+  6.32760540044689096e-02, 6.47013392832209017e-02, 6.61382200510255702e-02, 6.75867211558739411e-02,
+  6.90468693988278842e-02, 7.05186935025187284e-02, 7.20022240826794918e-02, 7.34974936225315234e-02,
+  7.50045364498633932e-02, 7.65233887166541088e-02, 7.80540883811067648e-02, 7.95966751919706395e-02,
+  8.11511906750410489e-02, 8.27176781217365126e-02, 8.42961825796618297e-02, 8.58867508450746053e-02,
+  8.74894314571802306e-02, 8.91042746941876490e-02, 9.07313325710650681e-02, 9.23706588389405919e-02,
+  9.40223089860987710e-02, 9.56863402405292163e-02, 9.73628115739883226e-02, 9.90517837075399477e-02,
+  1.00753319118545001e-01, 1.02467482049074188e-01, 1.04194338515721796e-01, 1.05933956320802311e-01,
+  1.07686405064914739e-01, 1.09451756160863237e-01, 1.11230082848925410e-01, 1.13021460213463015e-01,
+  1.14825965200872404e-01, 1.16643676638875135e-01, 1.18474675257151774e-01, 1.20319043709324627e-01,
+  1.22176866596297840e-01, 1.24048230490965763e-01, 1.25933223964303065e-01, 1.27831937612852747e-01,
+  1.29744464087630546e-01, 1.31670898124466806e-01, 1.33611336575809342e-01, 1.35565878444013660e-01,
+  1.37534624916149095e-01, 1.39517679400352007e-01, 1.41515147563760407e-01, 1.43527137372066238e-01,
+  1.45553759130724880e-01, 1.47595125527864302e-01, 1.49651351678938477e-01, 1.51722555173173845e-01,
+  1.53808856121859350e-01, 1.55910377208534928e-01, 1.58027243741136053e-01, 1.60159583706155212e-01,
+  1.62307527824885445e-01, 1.64471209611814106e-01, 1.66650765435239295e-01, 1.68846334580185331e-01,
+  1.71058059313697886e-01, 1.73286084952604003e-01, 1.75530559933826630e-01, 1.77791635887348476e-01,
+  1.80069467711924835e-01, 1.82364213653650881e-01, 1.84676035387494109e-01, 1.87005098101909184e-01,
+  1.89351570586658302e-01, 1.91715625323967076e-01, 1.94097438583153231e-01, 1.96497190518872378e-01,
+  1.98915065273133546e-01, 2.01351251081245269e-01, 2.03805940381861894e-01, 2.06279329931309235e-01,
+  2.08771620922378692e-01, 2.11283019107789544e-01, 2.13813734928530175e-01, 2.16363983647301389e-01,
+  2.18933985487297089e-01, 2.21523965776571685e-01, 2.24134155098257543e-01, 2.26764789446911585e-01,
+  2.29416110391285955e-01, 2.32088365243835693e-01, 2.34781807237294526e-01, 2.37496695708670186e-01,
+  2.40233296291031462e-01, 2.42991881113482550e-01, 2.45772729009743951e-01, 2.48576125735785858e-01,
+  2.51402364196987360e-01, 2.54251744685325187e-01, 2.57124575127127941e-01, 2.60021171341965340e-01,
+  2.62941857313280791e-01, 2.65886965471413717e-01, 2.68856836989702475e-01, 2.71851822094403950e-01,
+  2.74872280389216239e-01, 2.77918581195244541e-01, 2.80991103907308526e-01, 2.84090238367551429e-01,
+  2.87216385257380413e-01, 2.90369956508839122e-01, 2.93551375736594677e-01, 2.96761078691806124e-01,
+  2.99999513739235613e-01, 3.03267142359064323e-01, 3.06564439674986200e-01, 3.09891895010271701e-01,
+  3.13250012473625050e-01, 3.16639311576800198e-01, 3.20060327886097007e-01, 3.23513613710027459e-01,
+  3.26999738825628550e-01, 3.30519291246100522e-01, 3.34072878032672216e-01, 3.37661126153839308e-01,
+  3.41284683395388366e-01, 3.44944219324915868e-01, 3.48640426314874463e-01, 3.52374020628536733e-01,
+  3.56145743573661744e-01, 3.59956362729085655e-01, 3.63806673249939705e-01, 3.67697499257734817e-01,
+  3.71629695322144737e-01, 3.75604148041979891e-01, 3.79621777733577581e-01, 3.83683540235653209e-01,
+  3.87790428840570855e-01, 3.91943476363013077e-01, 3.96143757358174864e-01, 4.00392390502891826e-01,
+  4.04690541154555661e-01, 4.09039424104298055e-01, 4.13440306542758873e-01, 4.17894511258830093e-01,
+  4.22403420094118465e-01, 4.26968477678540237e-01, 4.31591195475498013e-01, 4.36273156168555332e-01,
+  4.41016018425484768e-01, 4.45821522080102228e-01, 4.50691493777514995e-01, 4.55627853134413063e-01,
+  4.60632619472968852e-01, 4.65707919194939979e-01, 4.70855993871896916e-01, 4.76079209138364601e-01,
+  4.81380064487366977e-01, 4.86761204082756582e-01, 4.92225428720234337e-01, 4.97775709089657958e-01,
+  5.03415200515766714e-01, 5.09147259383638584e-01, 5.14975461490076225e-01, 5.20903622603974048e-01,
+  5.26935821569183394e-01, 5.33076426344514576e-01, 5.39330123449928478e-01, 5.45701951379040096e-01,
+  5.52197338650130543e-01, 5.58822147306612838e-01, 5.65582722850729414e-01, 5.72485951810986604e-01,
+  5.79539328417515653e-01, 5.86751032207740497e-01, 5.94130018831275586e-01, 6.01686126900528362e-01,
+  6.09430204487332761e-01, 6.17374259859580943e-01, 6.25531642375396046e-01, 6.33917261235626639e-01,
+  6.42547852227750016e-01, 6.51442305956607792e-01, 6.60622075773704021e-01, 6.70111690338885846e-01,
+  6.79939405498997895e-01, 6.90138044589567623e-01, 7.00746098060345601e-01, 7.11809187067663940e-01,
+  7.23382049353243350e-01, 7.35531293788290408e-01, 7.48339319610238252e-01, 7.61910061273242123e-01,
+  7.76377711636374701e-01, 7.91920542530139882e-01, 8.08783975044814585e-01, 8.27321708541937983e-01,
+  8.48075596414898603e-01, 8.71949913503604068e-01, 9.00661391203950390e-01, 9.38263371663774048e-01,
+  1.00000000000000000e+00,
+];
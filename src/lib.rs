@@ -11,8 +11,22 @@
 #![warn(unused_qualifications)]
 #![warn(unused_results)]
 
+#[cfg(feature = "std")]
+mod alias;
+#[cfg(feature = "std")]
+mod distributions;
+#[cfg(feature = "getrandom")]
+mod reseeding;
+
 use core::array;
 use core::num::NonZeroU128;
+#[cfg(feature = "std")]
+use distributions::{ZIG_EXP_F, ZIG_EXP_X, ZIG_NORM_F, ZIG_NORM_X};
+
+#[cfg(feature = "std")]
+pub use alias::AliasTable;
+#[cfg(feature = "getrandom")]
+pub use reseeding::ReseedingRng;
 
 /// A fast non-cryptographic random number generator.
 
@@ -122,6 +136,210 @@ impl Rng {
       dst = &mut dst[1 ..];
     }
   }
+
+  /// Samples a `u64` from the uniform distribution on `0 .. n`.
+  ///
+  /// Uses Lemire's nearly-division-free method, so the common case costs a
+  /// single 128-bit multiply and no division.
+  ///
+  /// Panics if `n == 0`.
+
+  #[inline]
+  pub fn below(&mut self, n: u64) -> u64 {
+    assert!(n != 0, "below: n must be nonzero");
+
+    let x = self.u64();
+    let m = (x as u128) * (n as u128);
+    let mut hi = (m >> 64) as u64;
+    let mut lo = m as u64;
+
+    if lo < n {
+      let t = n.wrapping_neg() % n;
+      while lo < t {
+        let x = self.u64();
+        let m = (x as u128) * (n as u128);
+        hi = (m >> 64) as u64;
+        lo = m as u64;
+      }
+    }
+
+    hi
+  }
+
+  /// Samples a `u64` from the uniform distribution on `lo .. hi`.
+  ///
+  /// Panics if `lo >= hi`.
+
+  #[inline]
+  pub fn range(&mut self, lo: u64, hi: u64) -> u64 {
+    assert!(lo < hi, "range: lo must be less than hi");
+    lo + self.below(hi - lo)
+  }
+
+  /// Samples an `f64` from the uniform distribution on `[0, 1)`.
+  ///
+  /// Takes the top 53 bits of a `u64` draw, so every representable multiple
+  /// of 2^-53 in the range is equally likely.
+
+  #[inline(always)]
+  pub fn f64(&mut self) -> f64 {
+    let x = self.u64();
+    (x >> 11) as f64 * (1.0 / 9007199254740992.0)
+  }
+
+  /// Samples an `f32` from the uniform distribution on `[0, 1)`.
+  ///
+  /// Takes the top 24 bits of a `u64` draw, so every representable multiple
+  /// of 2^-24 in the range is equally likely.
+
+  #[inline(always)]
+  pub fn f32(&mut self) -> f32 {
+    let x = self.u64();
+    (x >> 40) as f32 * (1.0 / 16777216.0)
+  }
+
+  /// Samples an `f64` from the standard normal distribution (mean `0`,
+  /// variance `1`).
+  ///
+  /// Uses the Ziggurat algorithm, so the common case costs one `u64` draw
+  /// and no transcendental function calls.
+
+  #[cfg(feature = "std")]
+  pub fn normal(&mut self) -> f64 {
+    loop {
+      let bits = self.u64();
+      let i = (bits & 0xff) as usize;
+      let rest = bits >> 8;
+      let negative = rest & 1 != 0;
+      let u = (rest >> 3) as f64 * (1.0 / 9007199254740992.0);
+      let x = u * ZIG_NORM_X[i];
+
+      if x < ZIG_NORM_X[i + 1] {
+        return if negative { -x } else { x };
+      }
+
+      if i == 0 {
+        return ziggurat_normal_tail(self, negative);
+      }
+
+      let f = ZIG_NORM_F[i] + (ZIG_NORM_F[i + 1] - ZIG_NORM_F[i]) * self.f64();
+      if f < (-0.5 * x * x).exp() {
+        return if negative { -x } else { x };
+      }
+    }
+  }
+
+  /// Samples an `f64` from the exponential distribution with rate `1`.
+  ///
+  /// Uses the Ziggurat algorithm, so the common case costs one `u64` draw
+  /// and no transcendental function calls.
+
+  #[cfg(feature = "std")]
+  pub fn exp(&mut self) -> f64 {
+    loop {
+      let bits = self.u64();
+      let i = (bits & 0xff) as usize;
+      let u = (bits >> 11) as f64 * (1.0 / 9007199254740992.0);
+      let x = u * ZIG_EXP_X[i];
+
+      if x < ZIG_EXP_X[i + 1] {
+        return x;
+      }
+
+      if i == 0 {
+        return ZIG_EXP_X[0] + self.exp();
+      }
+
+      let f = ZIG_EXP_F[i] + (ZIG_EXP_F[i + 1] - ZIG_EXP_F[i]) * self.f64();
+      if f < (-x).exp() {
+        return x;
+      }
+    }
+  }
+
+  /// Shuffles the elements of a slice uniformly at random, in place.
+  ///
+  /// Uses the modern Fisher-Yates algorithm.
+
+  pub fn shuffle<T>(&mut self, slice: &mut [T]) {
+    let mut i = slice.len();
+
+    while i > 1 {
+      let j = self.below(i as u64) as usize;
+      i -= 1;
+      slice.swap(i, j);
+    }
+  }
+
+  /// Chooses a uniformly random element from a slice, or `None` if it is
+  /// empty.
+
+  pub fn choose<'a, T>(&mut self, slice: &'a [T]) -> Option<&'a T> {
+    if slice.is_empty() {
+      None
+    } else {
+      Some(&slice[self.below(slice.len() as u64) as usize])
+    }
+  }
+
+  /// Samples `k` elements from a slice without replacement, cloning each
+  /// one.
+  ///
+  /// Uses Floyd's algorithm, so it avoids an `O(slice.len())` scratch
+  /// buffer even when `k` is close to `slice.len()`.
+  ///
+  /// Panics if `k > slice.len()`.
+
+  #[cfg(feature = "std")]
+  pub fn sample<T: Clone>(&mut self, slice: &[T], k: usize) -> Vec<T> {
+    let len = slice.len();
+
+    assert!(k <= len, "sample: k must not exceed slice.len()");
+
+    let mut chosen = std::collections::HashSet::with_capacity(k);
+    let mut out = Vec::with_capacity(k);
+
+    for j in len - k .. len {
+      let t = self.below(j as u64 + 1) as usize;
+      let i = if chosen.contains(&t) { j } else { t };
+      let _ = chosen.insert(i);
+      out.push(slice[i].clone());
+    }
+
+    out
+  }
+
+  /// Returns an infinite iterator of i.i.d. `u64`s from the uniform
+  /// distribution.
+
+  pub fn iter_u64(&mut self) -> impl Iterator<Item = u64> + '_ {
+    core::iter::from_fn(move || Some(self.u64()))
+  }
+
+  /// Returns an infinite iterator of i.i.d. `f64`s from the uniform
+  /// distribution on `[0, 1)`.
+
+  pub fn iter_f64(&mut self) -> impl Iterator<Item = f64> + '_ {
+    core::iter::from_fn(move || Some(self.f64()))
+  }
+}
+
+/// Samples the tail of the half-normal distribution beyond `ZIG_NORM_X[0]`,
+/// via Marsaglia's fallback, and applies the given sign.
+
+#[cfg(feature = "std")]
+fn ziggurat_normal_tail(rng: &mut Rng, negative: bool) -> f64 {
+  let r = ZIG_NORM_X[0];
+
+  loop {
+    let x = -rng.f64().ln() / r;
+    let y = -rng.f64().ln();
+
+    if 2.0 * y > x * x {
+      let x = r + x;
+      return if negative { -x } else { x };
+    }
+  }
 }
 
 #[cfg(feature = "thread-local")]
@@ -181,4 +399,138 @@ pub mod thread_local {
   pub fn fill(dst: &mut [u8]) {
     with(|g| g.fill(dst))
   }
+
+  /// Samples a `u64` from the uniform distribution on `0 .. n`.
+  ///
+  /// Panics if `n == 0`.
+
+  pub fn below(n: u64) -> u64 {
+    with(|g| g.below(n))
+  }
+
+  /// Samples a `u64` from the uniform distribution on `lo .. hi`.
+  ///
+  /// Panics if `lo >= hi`.
+
+  pub fn range(lo: u64, hi: u64) -> u64 {
+    with(|g| g.range(lo, hi))
+  }
+
+  /// Samples an `f64` from the uniform distribution on `[0, 1)`.
+
+  pub fn f64() -> f64 {
+    with(Rng::f64)
+  }
+
+  /// Samples an `f32` from the uniform distribution on `[0, 1)`.
+
+  pub fn f32() -> f32 {
+    with(Rng::f32)
+  }
+
+  /// Samples an `f64` from the standard normal distribution (mean `0`,
+  /// variance `1`).
+
+  #[cfg(feature = "std")]
+  pub fn normal() -> f64 {
+    with(Rng::normal)
+  }
+
+  /// Samples an `f64` from the exponential distribution with rate `1`.
+
+  #[cfg(feature = "std")]
+  pub fn exp() -> f64 {
+    with(Rng::exp)
+  }
+
+  /// Shuffles the elements of a slice uniformly at random, in place.
+
+  pub fn shuffle<T>(slice: &mut [T]) {
+    with(|g| g.shuffle(slice))
+  }
+
+  /// Chooses a uniformly random element from a slice, or `None` if it is
+  /// empty.
+
+  pub fn choose<T>(slice: &[T]) -> Option<&T> {
+    with(|g| g.choose(slice))
+  }
+
+  /// Samples `k` elements from a slice without replacement, cloning each
+  /// one.
+  ///
+  /// Panics if `k > slice.len()`.
+
+  #[cfg(feature = "std")]
+  pub fn sample<T: Clone>(slice: &[T], k: usize) -> Vec<T> {
+    with(|g| g.sample(slice, k))
+  }
+
+  /// Returns an infinite iterator of i.i.d. `u64`s from the uniform
+  /// distribution.
+  ///
+  /// Splits off an owned generator up front, so iterating does not access
+  /// thread-local storage on every draw.
+
+  pub fn iter_u64() -> impl Iterator<Item = u64> {
+    let mut g = split();
+    core::iter::from_fn(move || Some(g.u64()))
+  }
+
+  /// Returns an infinite iterator of i.i.d. `f64`s from the uniform
+  /// distribution on `[0, 1)`.
+  ///
+  /// Splits off an owned generator up front, so iterating does not access
+  /// thread-local storage on every draw.
+
+  pub fn iter_f64() -> impl Iterator<Item = f64> {
+    let mut g = split();
+    core::iter::from_fn(move || Some(g.f64()))
+  }
+}
+
+#[cfg(feature = "rand-core")]
+impl rand_core::RngCore for Rng {
+  #[inline]
+  fn next_u32(&mut self) -> u32 {
+    (self.u64() >> 32) as u32
+  }
+
+  #[inline]
+  fn next_u64(&mut self) -> u64 {
+    self.u64()
+  }
+
+  #[inline]
+  fn fill_bytes(&mut self, dst: &mut [u8]) {
+    self.fill(dst)
+  }
+
+  #[inline]
+  fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), rand_core::Error> {
+    self.fill(dst);
+    Ok(())
+  }
+}
+
+#[cfg(feature = "rand-core")]
+impl rand_core::SeedableRng for Rng {
+  type Seed = [u8; 16];
+
+  #[inline]
+  fn from_seed(seed: Self::Seed) -> Self {
+    Self::from_seed(seed)
+  }
+
+  fn seed_from_u64(state: u64) -> Self {
+    let x = state;
+    let y = x ^ 0x9e3779b97f4a7c15;
+    let y = y.wrapping_mul(0xbf58476d1ce4e5b9);
+    let y = y ^ (y >> 31);
+
+    let mut seed = [0u8; 16];
+    seed[.. 8].copy_from_slice(&x.to_le_bytes());
+    seed[8 ..].copy_from_slice(&y.to_le_bytes());
+    Self::from_seed(seed)
+  }
 }
@@ -0,0 +1,65 @@
+//! A generator that periodically reseeds itself from the system entropy
+//! source.
+
+use crate::Rng;
+
+/// Wraps an [`Rng`] and periodically reseeds it from the system entropy
+/// source, once a threshold number of bytes have been generated since the
+/// last reseed.
+///
+/// This gives long-running services forward-secrecy-ish behavior without
+/// changing the hot path of the underlying generator.
+
+pub struct ReseedingRng {
+  rng: Rng,
+  threshold: u64,
+  since_reseed: u64,
+}
+
+impl ReseedingRng {
+  /// Creates a new generator that reseeds from the system entropy source
+  /// once `threshold` bytes have been generated since the last reseed.
+
+  pub fn new(threshold: u64) -> Self {
+    Self { rng: Rng::from_system_seed(), threshold, since_reseed: 0 }
+  }
+
+  /// Reseeds from the system entropy source immediately, regardless of how
+  /// many bytes have been generated since the last reseed.
+
+  pub fn reseed_now(&mut self) {
+    self.rng = Rng::from_system_seed();
+    self.since_reseed = 0;
+  }
+
+  #[inline]
+  fn reseed_if_due(&mut self) {
+    if self.since_reseed >= self.threshold {
+      self.reseed_now();
+    }
+  }
+
+  /// Samples a `u64` from the uniform distribution.
+
+  pub fn u64(&mut self) -> u64 {
+    self.reseed_if_due();
+    self.since_reseed += 8;
+    self.rng.u64()
+  }
+
+  /// Samples an array of i.i.d. `u64`s from the uniform distribution.
+
+  pub fn array_u64<const N: usize>(&mut self) -> [u64; N] {
+    self.reseed_if_due();
+    self.since_reseed += 8 * N as u64;
+    self.rng.array_u64()
+  }
+
+  /// Fills a slice with i.i.d. bytes sampled from the uniform distribution.
+
+  pub fn fill(&mut self, dst: &mut [u8]) {
+    self.reseed_if_due();
+    self.since_reseed += dst.len() as u64;
+    self.rng.fill(dst)
+  }
+}
@@ -0,0 +1,29 @@
+use xox_random::{AliasTable, Rng};
+
+#[test]
+fn uniform_weights_reach_every_index() {
+  let table = AliasTable::new(&[1.0, 1.0, 1.0]);
+  let mut g = Rng::from_seed(*b"autovivification");
+  let mut seen = [false; 3];
+
+  for _ in 0 .. 1000 {
+    seen[table.sample(&mut g)] = true;
+  }
+
+  assert_eq!(seen, [true, true, true]);
+}
+
+#[test]
+fn sample_frequencies_match_weights() {
+  let table = AliasTable::new(&[1.0, 3.0]);
+  let mut g = Rng::from_seed(*b"autovivification");
+  let n = 100_000;
+  let mut count = [0u32; 2];
+
+  for _ in 0 .. n {
+    count[table.sample(&mut g)] += 1;
+  }
+
+  let p1 = count[1] as f64 / n as f64;
+  assert!((p1 - 0.75).abs() < 0.01, "expected ~0.75, got {p1}");
+}
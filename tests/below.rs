@@ -0,0 +1,18 @@
+use xox_random::Rng;
+
+#[test]
+fn below_is_unbiased_across_buckets() {
+  let mut g = Rng::from_seed(*b"autovivification");
+  let n = 7;
+  let mut count = [0u32; 7];
+  let trials = 70_000;
+
+  for _ in 0 .. trials {
+    count[g.below(n) as usize] += 1;
+  }
+
+  let expected = trials as f64 / n as f64;
+  for c in count {
+    assert!((c as f64 - expected).abs() / expected < 0.05, "bucket count {c} far from expected {expected}");
+  }
+}
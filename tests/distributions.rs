@@ -0,0 +1,37 @@
+use xox_random::Rng;
+
+#[test]
+fn normal_matches_standard_moments() {
+  let mut g = Rng::from_seed(*b"autovivification");
+  let n = 200_000;
+  let mut sum = 0.0;
+  let mut sum_sq = 0.0;
+
+  for _ in 0 .. n {
+    let x = g.normal();
+    sum += x;
+    sum_sq += x * x;
+  }
+
+  let mean = sum / n as f64;
+  let variance = sum_sq / n as f64 - mean * mean;
+
+  assert!(mean.abs() < 0.02, "mean {mean} too far from 0");
+  assert!((variance - 1.0).abs() < 0.02, "variance {variance} too far from 1");
+}
+
+#[test]
+fn exp_matches_rate_one_mean() {
+  let mut g = Rng::from_seed(*b"autovivification");
+  let n = 200_000;
+  let mut sum = 0.0;
+
+  for _ in 0 .. n {
+    let x = g.exp();
+    assert!(x >= 0.0);
+    sum += x;
+  }
+
+  let mean = sum / n as f64;
+  assert!((mean - 1.0).abs() < 0.02, "mean {mean} too far from 1");
+}
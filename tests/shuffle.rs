@@ -0,0 +1,36 @@
+use xox_random::Rng;
+
+#[test]
+fn shuffle_is_a_permutation() {
+  let mut g = Rng::from_seed(*b"autovivification");
+  let mut v: Vec<u32> = (0 .. 20).collect();
+
+  g.shuffle(&mut v);
+
+  let mut sorted = v.clone();
+  sorted.sort();
+  assert_eq!(sorted, (0 .. 20).collect::<Vec<u32>>());
+}
+
+#[test]
+fn shuffle_places_each_element_in_every_slot_roughly_evenly() {
+  let mut g = Rng::from_seed(*b"autovivification");
+  let n = 5;
+  let mut count = [[0u32; 5]; 5];
+  let trials = 50_000;
+
+  for _ in 0 .. trials {
+    let mut v: Vec<usize> = (0 .. n).collect();
+    g.shuffle(&mut v);
+    for (slot, &value) in v.iter().enumerate() {
+      count[value][slot] += 1;
+    }
+  }
+
+  let expected = trials as f64 / n as f64;
+  for row in count {
+    for c in row {
+      assert!((c as f64 - expected).abs() / expected < 0.1, "count {c} far from expected {expected}");
+    }
+  }
+}